@@ -2,6 +2,14 @@ trait Circular {
 	fn get_radius(&self) -> f64;
 }
 
+/// A geometric query implemented by 2D pegs and peg adapters so a hole can
+/// reason about fit without knowing the concrete shape behind it.
+trait Shape {
+	fn area(&self) -> f64;
+	fn perimeter(&self) -> f64;
+	fn bounding_radius(&self) -> f64;
+}
+
 struct RoundHole {
 	radius: f64,
 }
@@ -11,8 +19,8 @@ impl RoundHole {
 		Self { radius }
 	}
 
-	fn fits<T: Circular>(&self, peg: T) -> bool {
-		self.radius >= peg.get_radius()
+	fn fits(&self, peg: &impl Shape) -> bool {
+		self.radius >= peg.bounding_radius()
 	}
 }
 
@@ -26,6 +34,20 @@ impl Circular for RoundPeg {
 	}
 }
 
+impl Shape for RoundPeg {
+	fn area(&self) -> f64 {
+		std::f64::consts::PI * self.radius * self.radius
+	}
+
+	fn perimeter(&self) -> f64 {
+		2.0 * std::f64::consts::PI * self.radius
+	}
+
+	fn bounding_radius(&self) -> f64 {
+		self.radius
+	}
+}
+
 struct SquarePeg {
 	width: f64,
 }
@@ -40,6 +62,20 @@ impl SquarePeg {
 	}
 }
 
+impl Shape for SquarePeg {
+	fn area(&self) -> f64 {
+		self.width * self.width
+	}
+
+	fn perimeter(&self) -> f64 {
+		4.0 * self.width
+	}
+
+	fn bounding_radius(&self) -> f64 {
+		self.width * f64::sqrt(2.0) / 2.0
+	}
+}
+
 struct SquarePegAdapter {
 	peg: SquarePeg,
 }
@@ -56,11 +92,435 @@ impl Circular for SquarePegAdapter {
 	}
 }
 
+impl Shape for SquarePegAdapter {
+	fn area(&self) -> f64 {
+		self.peg.area()
+	}
+
+	fn perimeter(&self) -> f64 {
+		self.peg.perimeter()
+	}
+
+	fn bounding_radius(&self) -> f64 {
+		self.peg.bounding_radius()
+	}
+}
+
+/// A circle described by its center and radius, as produced by [`welzl`].
+#[derive(Clone, Copy)]
+struct Circle {
+	center: (f64, f64),
+	radius: f64,
+}
+
+impl Circle {
+	fn zero() -> Self {
+		Self { center: (0.0, 0.0), radius: 0.0 }
+	}
+
+	fn contains(&self, p: (f64, f64)) -> bool {
+		const EPSILON: f64 = 1e-9;
+		dist(self.center, p) <= self.radius + EPSILON
+	}
+}
+
+fn dist(a: (f64, f64), b: (f64, f64)) -> f64 {
+	((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+}
+
+/// Smallest circle enclosing two points: the two become opposite ends of a diameter.
+fn circle_from_two(a: (f64, f64), b: (f64, f64)) -> Circle {
+	let center = ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0);
+	Circle { center, radius: dist(center, a) }
+}
+
+/// Circumcircle of three points via the perpendicular-bisector/determinant formula,
+/// falling back to the enclosing circle of the two farthest points when they're collinear.
+fn circle_from_three(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> Circle {
+	let d = 2.0 * (a.0 * (b.1 - c.1) + b.0 * (c.1 - a.1) + c.0 * (a.1 - b.1));
+	if d.abs() < 1e-9 {
+		let pairs = [(a, b), (a, c), (b, c)];
+		let (p, q) = pairs
+			.into_iter()
+			.max_by(|(p1, q1), (p2, q2)| dist(*p1, *q1).partial_cmp(&dist(*p2, *q2)).unwrap())
+			.unwrap();
+		return circle_from_two(p, q);
+	}
+
+	let a_sq = a.0 * a.0 + a.1 * a.1;
+	let b_sq = b.0 * b.0 + b.1 * b.1;
+	let c_sq = c.0 * c.0 + c.1 * c.1;
+
+	let ux = (a_sq * (b.1 - c.1) + b_sq * (c.1 - a.1) + c_sq * (a.1 - b.1)) / d;
+	let uy = (a_sq * (c.0 - b.0) + b_sq * (a.0 - c.0) + c_sq * (b.0 - a.0)) / d;
+	let center = (ux, uy);
+	Circle { center, radius: dist(center, a) }
+}
+
+fn trivial(boundary: &[(f64, f64)]) -> Circle {
+	match boundary.len() {
+		0 => Circle::zero(),
+		1 => Circle { center: boundary[0], radius: 0.0 },
+		2 => circle_from_two(boundary[0], boundary[1]),
+		_ => circle_from_three(boundary[0], boundary[1], boundary[2]),
+	}
+}
+
+/// Welzl's randomized incremental algorithm for the minimum enclosing circle.
+/// `points` still to be considered, `boundary` points already known to lie on it.
+fn welzl(points: &mut Vec<(f64, f64)>, mut boundary: Vec<(f64, f64)>) -> Circle {
+	if points.is_empty() || boundary.len() == 3 {
+		return trivial(&boundary);
+	}
+
+	let p = points.pop().unwrap();
+	let d = welzl(points, boundary.clone());
+
+	if d.contains(p) {
+		points.push(p);
+		return d;
+	}
+
+	boundary.push(p);
+	let d = welzl(points, boundary.clone());
+	points.push(p);
+	d
+}
+
+/// Deterministic xorshift32 PRNG, just enough to shuffle points before running
+/// [`welzl`] so its expected O(n) bound holds without pulling in a `rand` dependency.
+struct Xorshift32 {
+	state: u32,
+}
+
+impl Xorshift32 {
+	fn new(seed: u32) -> Self {
+		Self { state: if seed == 0 { 0x9e3779b9 } else { seed } }
+	}
+
+	fn next(&mut self) -> u32 {
+		self.state ^= self.state << 13;
+		self.state ^= self.state >> 17;
+		self.state ^= self.state << 5;
+		self.state
+	}
+}
+
+fn shuffled(points: &[(f64, f64)]) -> Vec<(f64, f64)> {
+	let mut shuffled = points.to_vec();
+	let mut rng = Xorshift32::new(points.len() as u32);
+	for i in (1..shuffled.len()).rev() {
+		let j = (rng.next() as usize) % (i + 1);
+		shuffled.swap(i, j);
+	}
+	shuffled
+}
+
+struct PolygonPeg {
+	vertices: Vec<(f64, f64)>,
+}
+
+impl PolygonPeg {
+	fn new(vertices: Vec<(f64, f64)>) -> Self {
+		Self { vertices }
+	}
+}
+
+struct PolygonPegAdapter {
+	peg: PolygonPeg,
+}
+
+impl PolygonPegAdapter {
+	fn new(peg: PolygonPeg) -> Self {
+		Self { peg }
+	}
+
+	fn min_enclosing_circle(&self) -> Circle {
+		let mut points = shuffled(&self.peg.vertices);
+		welzl(&mut points, Vec::new())
+	}
+}
+
+impl Circular for PolygonPegAdapter {
+	fn get_radius(&self) -> f64 {
+		self.min_enclosing_circle().radius
+	}
+}
+
+impl Shape for PolygonPegAdapter {
+	fn area(&self) -> f64 {
+		let v = &self.peg.vertices;
+		if v.len() < 3 {
+			return 0.0;
+		}
+		let mut sum = 0.0;
+		for i in 0..v.len() {
+			let (x1, y1) = v[i];
+			let (x2, y2) = v[(i + 1) % v.len()];
+			sum += x1 * y2 - x2 * y1;
+		}
+		sum.abs() / 2.0
+	}
+
+	fn perimeter(&self) -> f64 {
+		let v = &self.peg.vertices;
+		if v.len() < 2 {
+			return 0.0;
+		}
+		(0..v.len()).map(|i| dist(v[i], v[(i + 1) % v.len()])).sum()
+	}
+
+	fn bounding_radius(&self) -> f64 {
+		self.get_radius()
+	}
+}
+
+struct RoundedRectPeg {
+	width: f64,
+	height: f64,
+	corner_radius: f64,
+}
+
+impl RoundedRectPeg {
+	/// Rejects negative dimensions outright; a corner radius that would make
+	/// adjacent corners overlap is scaled down rather than rejected, following
+	/// the `roundRect`/CSS border-radius clamping rule.
+	fn new(width: f64, height: f64, corner_radius: f64) -> Result<Self, String> {
+		if width < 0.0 || height < 0.0 || corner_radius < 0.0 {
+			return Err("width, height, and corner_radius must be non-negative".to_string());
+		}
+
+		let limit = (width / 2.0).min(height / 2.0);
+		let corner_radius = corner_radius.min(limit);
+
+		Ok(Self { width, height, corner_radius })
+	}
+
+	fn is_rounded(&self) -> bool {
+		self.corner_radius > 0.0
+	}
+}
+
+struct RoundedRectPegAdapter {
+	peg: RoundedRectPeg,
+}
+
+impl RoundedRectPegAdapter {
+	fn new(peg: RoundedRectPeg) -> Self {
+		Self { peg }
+	}
+
+	fn is_rounded(&self) -> bool {
+		self.peg.is_rounded()
+	}
+}
+
+impl Circular for RoundedRectPegAdapter {
+	fn get_radius(&self) -> f64 {
+		let r = self.peg.corner_radius;
+		let corner_center_offset = dist(
+			(0.0, 0.0),
+			(self.peg.width / 2.0 - r, self.peg.height / 2.0 - r),
+		);
+		corner_center_offset + r
+	}
+}
+
+impl Shape for RoundedRectPegAdapter {
+	fn area(&self) -> f64 {
+		let r = self.peg.corner_radius;
+		self.peg.width * self.peg.height - (4.0 - std::f64::consts::PI) * r * r
+	}
+
+	fn perimeter(&self) -> f64 {
+		let r = self.peg.corner_radius;
+		2.0 * (self.peg.width + self.peg.height) + (2.0 * std::f64::consts::PI - 8.0) * r
+	}
+
+	fn bounding_radius(&self) -> f64 {
+		self.get_radius()
+	}
+}
+
+struct RoundedHole {
+	radius: f64,
+	corner_radius: f64,
+}
+
+impl RoundedHole {
+	fn new(radius: f64, corner_radius: f64) -> Self {
+		Self { radius, corner_radius }
+	}
+
+	fn is_rounded(&self) -> bool {
+		self.corner_radius > 0.0
+	}
+
+	fn fits(&self, peg: &impl Shape) -> bool {
+		self.radius >= peg.bounding_radius()
+	}
+}
+
+/// The 3D counterpart of [`Shape`]: enough to check a solid against a
+/// cylindrical hole without knowing its concrete form.
+trait Volumetric {
+	fn bounding_radius(&self) -> f64;
+	fn half_height(&self) -> f64;
+}
+
+struct Cylinder {
+	half_height: f64,
+	radius: f64,
+}
+
+impl Volumetric for Cylinder {
+	fn bounding_radius(&self) -> f64 {
+		self.radius
+	}
+
+	fn half_height(&self) -> f64 {
+		self.half_height
+	}
+}
+
+struct Cone {
+	half_height: f64,
+	radius: f64,
+}
+
+impl Volumetric for Cone {
+	fn bounding_radius(&self) -> f64 {
+		self.radius
+	}
+
+	fn half_height(&self) -> f64 {
+		self.half_height
+	}
+}
+
+/// A [`Cylinder`] inflated by a uniform border radius on its edges, mirroring
+/// the round-collider shapes of physics engines like Rapier.
+struct RoundCylinder {
+	base: Cylinder,
+	border_radius: f64,
+}
+
+impl Volumetric for RoundCylinder {
+	fn bounding_radius(&self) -> f64 {
+		self.base.radius + self.border_radius
+	}
+
+	fn half_height(&self) -> f64 {
+		self.base.half_height + self.border_radius
+	}
+}
+
+/// A 2D [`SquarePeg`] extruded into a square prism, so planar pegs can be
+/// reused in the volumetric fit checks below.
+struct SquarePrismPeg {
+	base: SquarePeg,
+	half_height: f64,
+}
+
+struct SquarePrismPegAdapter {
+	peg: SquarePrismPeg,
+}
+
+impl SquarePrismPegAdapter {
+	fn new(peg: SquarePrismPeg) -> Self {
+		Self { peg }
+	}
+}
+
+impl Volumetric for SquarePrismPegAdapter {
+	fn bounding_radius(&self) -> f64 {
+		self.peg.base.bounding_radius()
+	}
+
+	fn half_height(&self) -> f64 {
+		self.peg.half_height
+	}
+}
+
+struct CylindricalHole {
+	radius: f64,
+	depth: f64,
+}
+
+impl CylindricalHole {
+	fn new(radius: f64, depth: f64) -> Self {
+		Self { radius, depth }
+	}
+
+	fn fits3d(&self, peg: &impl Volumetric) -> bool {
+		self.radius >= peg.bounding_radius() && self.depth >= 2.0 * peg.half_height()
+	}
+}
+
+struct RegularPolygonPeg {
+	sides: u32,
+	circumradius: f64,
+}
+
+impl RegularPolygonPeg {
+	fn new(sides: u32, circumradius: f64) -> Result<Self, String> {
+		if sides < 3 {
+			return Err("a regular polygon needs at least 3 sides".to_string());
+		}
+
+		Ok(Self { sides, circumradius })
+	}
+}
+
+struct RegularPolygonPegAdapter {
+	peg: RegularPolygonPeg,
+}
+
+impl RegularPolygonPegAdapter {
+	fn new(peg: RegularPolygonPeg) -> Self {
+		Self { peg }
+	}
+
+	fn side_length(&self) -> f64 {
+		2.0 * self.peg.circumradius * (std::f64::consts::PI / self.peg.sides as f64).sin()
+	}
+
+	fn inradius(&self) -> f64 {
+		self.peg.circumradius * (std::f64::consts::PI / self.peg.sides as f64).cos()
+	}
+
+	fn area(&self) -> f64 {
+		0.5 * self.peg.sides as f64
+			* self.peg.circumradius.powi(2)
+			* (2.0 * std::f64::consts::PI / self.peg.sides as f64).sin()
+	}
+}
+
+impl Circular for RegularPolygonPegAdapter {
+	fn get_radius(&self) -> f64 {
+		self.peg.circumradius
+	}
+}
+
+impl Shape for RegularPolygonPegAdapter {
+	fn area(&self) -> f64 {
+		self.area()
+	}
+
+	fn perimeter(&self) -> f64 {
+		self.peg.sides as f64 * self.side_length()
+	}
+
+	fn bounding_radius(&self) -> f64 {
+		self.peg.circumradius
+	}
+}
+
 pub fn run() {
 	let hole = RoundHole::new(5.0);
 
 	let rpeg = RoundPeg {radius: 5.0};
-	println!("Round peg r5 fits round hole r5: {}", hole.fits(rpeg));
+	println!("Round peg r5 fits round hole r5: {}", hole.fits(&rpeg));
 
 
 	let small_sqpeg = SquarePeg::new(5.0);
@@ -69,6 +529,33 @@ pub fn run() {
 	let large_sqpeg = SquarePeg::new(10.0);
 	let large_sqpeg_adapter = SquarePegAdapter::new(large_sqpeg);
 
-	println!("Square peg w5 fits round hole r5: {}", hole.fits(small_sqpeg_adapter));
-	println!("Square peg w10 doesn't fit round hole r5: {}", !hole.fits(large_sqpeg_adapter));
+	println!("Square peg w5 fits round hole r5: {}", hole.fits(&small_sqpeg_adapter));
+	println!("Square peg w10 doesn't fit round hole r5: {}", !hole.fits(&large_sqpeg_adapter));
+
+	let triangle_peg = PolygonPeg::new(vec![(0.0, 0.0), (6.0, 0.0), (3.0, 4.0)]);
+	let triangle_adapter = PolygonPegAdapter::new(triangle_peg);
+	println!("Triangle peg fits round hole r5: {}", hole.fits(&triangle_adapter));
+
+	let rounded_hole = RoundedHole::new(7.0, 1.0);
+	let rrect_peg = RoundedRectPeg::new(8.0, 6.0, 1.0).expect("valid rounded rect dimensions");
+	let rrect_adapter = RoundedRectPegAdapter::new(rrect_peg);
+	println!("Rounded rect peg is rounded: {}", rrect_adapter.is_rounded());
+	println!("Rounded rect peg fits rounded hole r7: {}", rounded_hole.fits(&rrect_adapter));
+
+	let cyl_hole = CylindricalHole::new(5.0, 10.0);
+
+	let cone = Cone { half_height: 4.0, radius: 4.0 };
+	println!("Cone fits cylindrical hole r5 d10: {}", cyl_hole.fits3d(&cone));
+
+	let prism_peg = SquarePrismPeg { base: SquarePeg::new(5.0), half_height: 6.0 };
+	let prism_adapter = SquarePrismPegAdapter::new(prism_peg);
+	println!(
+		"Square prism peg doesn't fit cylindrical hole r5 d10: {}",
+		!cyl_hole.fits3d(&prism_adapter)
+	);
+
+	let hex_peg = RegularPolygonPeg::new(6, 5.0).expect("hexagon needs at least 3 sides");
+	let hex_adapter = RegularPolygonPegAdapter::new(hex_peg);
+	println!("Hexagon peg fits round hole r5: {}", hole.fits(&hex_adapter));
+	println!("Hexagon side length: {}", hex_adapter.side_length());
 }
\ No newline at end of file